@@ -152,8 +152,132 @@ impl<'a, T: ?Sized + 'a> RangeArgument<T> for (Bound<&'a T>, Bound<&'a T>) {
     }
 }
 
+/// Methods for ranges whose endpoints index into a slice-like collection.
+///
+/// This is implemented for every type that implements `RangeArgument<usize>`
+/// (that is, for `..`, `a..`, `..b`, `a..b` and the rest), and gives a single,
+/// audited place to turn any of those bound combinations into a concrete,
+/// bounds-checked `[start, end)` pair. Collections like `Vec::drain` and
+/// `String::drain` should call `normalize` rather than re-deriving this logic
+/// themselves.
+pub trait IndexRangeArgument: RangeArgument<usize> {
+    /// Resolves `self` against a collection of length `len`, returning the
+    /// equivalent half-open `Range<usize>`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than the end, if the end
+    /// is greater than `len`, or if an `Excluded` start or `Included` end
+    /// bound is `usize::MAX` (since including that index would overflow).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(alloc)]
+    /// #![feature(collections_range)]
+    ///
+    /// extern crate alloc;
+    ///
+    /// # fn main() {
+    /// use alloc::range::IndexRangeArgument;
+    ///
+    /// assert_eq!((..).normalize(5), 0..5);
+    /// assert_eq!((2..7).normalize(10), 2..7);
+    /// # }
+    /// ```
+    fn normalize(&self, len: usize) -> Range<usize> {
+        let start = match self.start() {
+            Unbounded => 0,
+            Included(&start) => start,
+            Excluded(&start) => {
+                start.checked_add(1).expect("attempted to index slice from after maximum usize")
+            }
+        };
+
+        let end = match self.end() {
+            Unbounded => len,
+            Excluded(&end) => end,
+            Included(&end) => {
+                end.checked_add(1).expect("attempted to index slice up to maximum usize")
+            }
+        };
+
+        if start > end {
+            panic!("range start is greater than range end");
+        }
+        if end > len {
+            panic!("range end is greater than length");
+        }
+
+        start..end
+    }
+}
+
+impl<R: ?Sized + RangeArgument<usize>> IndexRangeArgument for R {}
+
 pub trait OrderedRangeArgument<T: Ord + ?Sized> {
     fn range_cmp(&self, &T) -> RelationToRange;
+
+    /// Returns the bound pair describing the overlap between `self` and
+    /// `other`, or `None` if the two ranges are disjoint.
+    ///
+    /// The start of the intersection is whichever of the two start bounds is
+    /// tighter (the larger value, with an `Excluded` bound beating an
+    /// `Included` bound at the same value); the end is chosen symmetrically.
+    /// Since `(Bound<&T>, Bound<&T>)` itself implements `RangeArgument`, the
+    /// result can be used as a range in its own right.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(alloc)]
+    /// #![feature(collections_range)]
+    ///
+    /// extern crate alloc;
+    ///
+    /// # fn main() {
+    /// use alloc::range::OrderedRangeArgument;
+    /// use alloc::Bound::*;
+    ///
+    /// assert_eq!((2..8).intersect(&(5..10)), Some((Included(&5), Excluded(&8))));
+    /// assert_eq!((0..5).intersect(&(5..10)), None);
+    /// # }
+    /// ```
+    fn intersect<'a, R: RangeArgument<T>>(&'a self,
+                                          other: &'a R)
+                                          -> Option<(Bound<&'a T>, Bound<&'a T>)>;
+
+    /// Returns `true` if `self` and `other` share at least one value.
+    fn overlaps<R: RangeArgument<T>>(&self, other: &R) -> bool;
+
+    /// Returns `true` if `value` lies within `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(alloc)]
+    /// #![feature(collections_range)]
+    /// #![feature(inclusive_range)]
+    ///
+    /// extern crate alloc;
+    ///
+    /// # fn main() {
+    /// use alloc::range::OrderedRangeArgument;
+    /// use std::ops::RangeInclusive;
+    ///
+    /// assert!(!(0..10).contains(&10));
+    /// assert!(RangeInclusive { start: 0, end: 10 }.contains(&10));
+    /// # }
+    /// ```
+    fn contains(&self, value: &T) -> bool;
+
+    /// Returns `true` if `self` contains no values at all.
+    ///
+    /// `Excluded(a)..Excluded(a)`, `Included(a)..Excluded(a)` and
+    /// `Excluded(a)..Included(a)` are all empty, while `Included(a)..Included(a)`
+    /// holds exactly the value `a`. A range with an `Unbounded` start or end
+    /// is never empty.
+    fn is_empty(&self) -> bool;
 }
 
 impl<T, R> OrderedRangeArgument<T> for R
@@ -189,12 +313,91 @@ where R: RangeArgument<T>, T: Ord + ?Sized {
 
         return Inside;
     }
+
+    fn intersect<'a, Q: RangeArgument<T>>(&'a self,
+                                          other: &'a Q)
+                                          -> Option<(Bound<&'a T>, Bound<&'a T>)> {
+        let start = tighter_start(self.start(), other.start());
+        let end = tighter_end(self.end(), other.end());
+
+        if range_is_empty(start, end) {
+            None
+        } else {
+            Some((start, end))
+        }
+    }
+
+    fn overlaps<Q: RangeArgument<T>>(&self, other: &Q) -> bool {
+        self.intersect(other).is_some()
+    }
+
+    fn contains(&self, value: &T) -> bool {
+        self.range_cmp(value) == Inside
+    }
+
+    fn is_empty(&self) -> bool {
+        range_is_empty(self.start(), self.end())
+    }
+}
+
+/// Picks whichever of two start bounds is tighter: the larger value, with an
+/// `Excluded` bound winning ties against an `Included` bound at the same
+/// value (excluding a value is a stricter constraint than including it).
+fn tighter_start<'a, T: Ord + ?Sized>(a: Bound<&'a T>, b: Bound<&'a T>) -> Bound<&'a T> {
+    match (a, b) {
+        (Unbounded, bound) | (bound, Unbounded) => bound,
+        (Included(x), Included(y)) => Included(if x >= y { x } else { y }),
+        (Excluded(x), Excluded(y)) => Excluded(if x >= y { x } else { y }),
+        (Included(included), Excluded(excluded)) |
+        (Excluded(excluded), Included(included)) => {
+            if included > excluded {
+                Included(included)
+            } else {
+                Excluded(excluded)
+            }
+        }
+    }
+}
+
+/// Picks whichever of two end bounds is tighter: the smaller value, with an
+/// `Excluded` bound winning ties against an `Included` bound at the same
+/// value.
+fn tighter_end<'a, T: Ord + ?Sized>(a: Bound<&'a T>, b: Bound<&'a T>) -> Bound<&'a T> {
+    match (a, b) {
+        (Unbounded, bound) | (bound, Unbounded) => bound,
+        (Included(x), Included(y)) => Included(if x <= y { x } else { y }),
+        (Excluded(x), Excluded(y)) => Excluded(if x <= y { x } else { y }),
+        (Included(included), Excluded(excluded)) |
+        (Excluded(excluded), Included(included)) => {
+            if included < excluded {
+                Included(included)
+            } else {
+                Excluded(excluded)
+            }
+        }
+    }
+}
+
+/// Reports whether a start/end bound pair describes an empty range. When both
+/// bounds are `Included` the range is empty only if the start is above the
+/// end; with any `Excluded` bound it is empty if the start is not strictly
+/// below the end. `Unbounded` on either side can never make a range empty.
+fn range_is_empty<T: Ord + ?Sized>(start: Bound<&T>, end: Bound<&T>) -> bool {
+    match (start, end) {
+        (Unbounded, _) | (_, Unbounded) => false,
+        (Included(s), Included(e)) => s > e,
+        (Included(s), Excluded(e)) |
+        (Excluded(s), Included(e)) |
+        (Excluded(s), Excluded(e)) => s >= e,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use core::ops::{RangeFull, Range, RangeTo, RangeFrom, RangeInclusive, RangeToInclusive};
-    use super::{RangeArgument, OrderedRangeArgument};
+    use super::{RangeArgument, OrderedRangeArgument, IndexRangeArgument};
+    use super::{Excluded, Included, Unbounded};
+    use super::{tighter_start, tighter_end};
 
     #[test]
     fn test_ordered_range_inclusive_lower() {
@@ -203,5 +406,94 @@ mod tests {
         assert_eq!(range.range_cmp(4), Inside);
         assert_eq!(range.range_cmp(5), Inside);
     }
+
+    #[test]
+    fn test_normalize_bound_combinations() {
+        assert_eq!((..).normalize(5), 0..5);
+        assert_eq!((3..).normalize(10), 3..10);
+        assert_eq!((..7).normalize(10), 0..7);
+        assert_eq!((3..7).normalize(10), 3..7);
+        assert_eq!(RangeInclusive { start: 3, end: 7 }.normalize(10), 3..8);
+        assert_eq!(RangeToInclusive { end: 7 }.normalize(10), 0..8);
+    }
+
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    fn test_normalize_start_after_end_panics() {
+        (5..3).normalize(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end is greater than length")]
+    fn test_normalize_end_past_len_panics() {
+        (0..10).normalize(5);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice from after maximum usize")]
+    fn test_normalize_excluded_start_overflow_panics() {
+        (Excluded(usize::MAX), Unbounded).normalize(10);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempted to index slice up to maximum usize")]
+    fn test_normalize_included_end_overflow_panics() {
+        (Unbounded, Included(usize::MAX)).normalize(10);
+    }
+
+    #[test]
+    fn test_tighter_start_ties_prefer_excluded() {
+        assert_eq!(tighter_start(Included(&5), Excluded(&5)), Excluded(&5));
+        assert_eq!(tighter_start(Excluded(&5), Included(&5)), Excluded(&5));
+        assert_eq!(tighter_start(Included(&3), Included(&5)), Included(&5));
+        assert_eq!(tighter_start(Unbounded, Included(&5)), Included(&5));
+        assert_eq!(tighter_start(Included(&5), Unbounded), Included(&5));
+    }
+
+    #[test]
+    fn test_tighter_end_ties_prefer_excluded() {
+        assert_eq!(tighter_end(Included(&5), Excluded(&5)), Excluded(&5));
+        assert_eq!(tighter_end(Excluded(&5), Included(&5)), Excluded(&5));
+        assert_eq!(tighter_end(Included(&5), Included(&3)), Included(&3));
+        assert_eq!(tighter_end(Unbounded, Included(&5)), Included(&5));
+        assert_eq!(tighter_end(Included(&5), Unbounded), Included(&5));
+    }
+
+    #[test]
+    fn test_intersect_overlapping_ranges() {
+        assert_eq!((2..8).intersect(&(5..10)), Some((Included(&5), Excluded(&8))));
+        assert!((2..8).overlaps(&(5..10)));
+    }
+
+    #[test]
+    fn test_intersect_disjoint_ranges_is_none() {
+        assert_eq!((0..5).intersect(&(5..10)), None);
+        assert!(!(0..5).overlaps(&(5..10)));
+    }
+
+    #[test]
+    fn test_intersect_unbounded_side_takes_other_bounds() {
+        assert_eq!((..).intersect(&(3..7)), Some((Included(&3), Excluded(&7))));
+    }
+
+    #[test]
+    fn test_is_empty_truth_table() {
+        assert!((Excluded(5), Excluded(5)).is_empty());
+        assert!((Included(5), Excluded(5)).is_empty());
+        assert!((Excluded(5), Included(5)).is_empty());
+        assert!(!(Included(5), Included(5)).is_empty());
+    }
+
+    #[test]
+    fn test_is_empty_never_true_with_unbounded_side() {
+        assert!(!(Unbounded, Excluded(5)).is_empty());
+        assert!(!(Included(5), Unbounded).is_empty());
+    }
+
+    #[test]
+    fn test_contains_boundaries() {
+        assert!(!(0..10).contains(&10));
+        assert!(RangeInclusive { start: 0, end: 10 }.contains(&10));
+    }
 }
 